@@ -1,54 +1,425 @@
 use num::traits::{CheckedAdd, CheckedSub, Zero};
 use std::collections::BTreeMap;
 
+#[derive(Debug)]
 pub enum BalanceError<AccountId> {
     InsufficientBalance,
     BalanceOverflow(AccountId),
+    ExistentialDeposit,
+    LiquidityRestrictions,
+}
+
+/// A single named hold on part of an account's free balance. The locked
+/// funds stay in the owner's account but cannot be spent while the lock
+/// is in place.
+#[derive(Clone, Debug)]
+pub struct BalanceLock<Balance> {
+    pub id: [u8; 8],
+    pub amount: Balance,
 }
 
 pub trait Config: crate::system::Config {
-    type Balance: Zero + CheckedSub + CheckedAdd + Copy;
+    type Balance: Zero + CheckedSub + CheckedAdd + Copy + PartialOrd + std::fmt::Debug;
+
+    /// The minimum balance an account is allowed to hold. Any account whose
+    /// balance would drop below this is reaped instead of left as dust.
+    const EXISTENTIAL_DEPOSIT: Self::Balance;
+
+    /// The only account allowed to dispatch `Call::Slash`.
+    fn slash_origin() -> Self::AccountId;
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct Pallet<T: Config> {
     balances: BTreeMap<T::AccountId, T::Balance>,
+    reserved: BTreeMap<T::AccountId, T::Balance>,
+    locks: BTreeMap<T::AccountId, Vec<BalanceLock<T::Balance>>>,
+    total_issuance: T::Balance,
 }
 
 impl<T: Config> Pallet<T> {
     pub fn new() -> Self {
         Self {
             balances: BTreeMap::new(),
+            reserved: BTreeMap::new(),
+            locks: BTreeMap::new(),
+            total_issuance: T::Balance::zero(),
         }
     }
 
     pub fn set_balance(&mut self, who: &T::AccountId, amount: T::Balance) {
-        self.balances.insert(who.clone(), amount);
+        let previous = self.balance(who);
+        self.adjust_issuance(previous, amount);
+        self.apply_balance(who, amount);
     }
 
     pub fn balance(&self, who: &T::AccountId) -> T::Balance {
         *self.balances.get(who).unwrap_or(&T::Balance::zero())
     }
 
+    /// The sum of every free and reserved balance currently in existence.
+    pub fn total_issuance(&self) -> T::Balance {
+        self.total_issuance
+    }
+
+    /// Force `total_issuance` to an arbitrary value, bypassing the
+    /// bookkeeping every other mutator goes through. Exists only so
+    /// integration tests can simulate the balances invariant having been
+    /// broken elsewhere and observe `try_state` catching it.
+    #[cfg(test)]
+    pub(crate) fn test_set_total_issuance(&mut self, amount: T::Balance) {
+        self.total_issuance = amount;
+    }
+
+    pub fn balance_reserved(&self, who: &T::AccountId) -> T::Balance {
+        *self.reserved.get(who).unwrap_or(&T::Balance::zero())
+    }
+
+    /// The amount of `who`'s free balance that is currently unspendable.
+    /// Locks overlap rather than sum, so this is the largest of them.
+    pub fn locked_balance(&self, who: &T::AccountId) -> T::Balance {
+        self.locks
+            .get(who)
+            .into_iter()
+            .flatten()
+            .map(|lock| lock.amount)
+            .fold(T::Balance::zero(), |max, amount| {
+                if amount > max {
+                    amount
+                } else {
+                    max
+                }
+            })
+    }
+
+    /// Burn up to `amount` from `who`, pulling from their reserved balance
+    /// first and then their free balance, and shrinking total issuance by
+    /// whatever was actually removed. Returns the shortfall that could not
+    /// be slashed because `who` held less than `amount`.
+    pub fn slash(&mut self, who: &T::AccountId, amount: T::Balance) -> T::Balance {
+        let reserved = self.balance_reserved(who);
+        let from_reserved = if amount > reserved { reserved } else { amount };
+        let remaining = amount.checked_sub(&from_reserved).unwrap_or(T::Balance::zero());
+        self.write_reserved(
+            who,
+            reserved.checked_sub(&from_reserved).unwrap_or(T::Balance::zero()),
+        );
+
+        let free = self.balance(who);
+        let from_free = if remaining > free { free } else { remaining };
+        let shortfall = remaining.checked_sub(&from_free).unwrap_or(T::Balance::zero());
+        let new_free = free.checked_sub(&from_free).unwrap_or(T::Balance::zero());
+
+        let slashed = from_reserved
+            .checked_add(&from_free)
+            .unwrap_or(from_reserved);
+        self.total_issuance = self
+            .total_issuance
+            .checked_sub(&slashed)
+            .unwrap_or(self.total_issuance);
+
+        // Slashing is destructive, so any dust left below the existential
+        // deposit is reaped and burned too, same as `transfer`'s sender-side
+        // check - `apply_balance` already does exactly that.
+        self.apply_balance(who, new_free);
+
+        shortfall
+    }
+
+    /// Insert or overwrite the lock identified by `id` on `who`'s account.
+    pub fn set_lock(&mut self, id: [u8; 8], who: &T::AccountId, amount: T::Balance) {
+        let locks = self.locks.entry(who.clone()).or_default();
+        match locks.iter_mut().find(|lock| lock.id == id) {
+            Some(lock) => lock.amount = amount,
+            None => locks.push(BalanceLock { id, amount }),
+        }
+    }
+
+    /// Remove the lock identified by `id` from `who`'s account, if any.
+    pub fn remove_lock(&mut self, id: [u8; 8], who: &T::AccountId) {
+        if let Some(locks) = self.locks.get_mut(who) {
+            locks.retain(|lock| lock.id != id);
+            if locks.is_empty() {
+                self.locks.remove(who);
+            }
+        }
+    }
+
+    /// Move `amount` from `who`'s free balance into their reserved balance.
+    pub fn reserve(
+        &mut self,
+        who: &T::AccountId,
+        amount: T::Balance,
+    ) -> Result<(), BalanceError<T::AccountId>> {
+        let new_free = self
+            .balance(who)
+            .checked_sub(&amount)
+            .ok_or(BalanceError::InsufficientBalance)?;
+
+        // Reserving cannot leave the free side with an unreapable dust
+        // balance - same rule `transfer` enforces on its sender.
+        if new_free < T::EXISTENTIAL_DEPOSIT && !new_free.is_zero() {
+            return Err(BalanceError::ExistentialDeposit);
+        }
+
+        let new_reserved = self
+            .balance_reserved(who)
+            .checked_add(&amount)
+            .ok_or(BalanceError::BalanceOverflow(who.clone()))?;
+
+        self.write_free(who, new_free);
+        self.write_reserved(who, new_reserved);
+
+        Ok(())
+    }
+
+    /// Move up to `amount` from `who`'s reserved balance back into their free
+    /// balance, saturating at whatever is actually reserved. Returns the part
+    /// of `amount` that could not be unreserved.
+    ///
+    /// Total issuance stays invariant across reserve/unreserve: if moving the
+    /// funds back would create a sub-existential-deposit free balance for a
+    /// fresh account, the funds are left in `reserved` - and reported back as
+    /// not unreserved - rather than burned.
+    pub fn unreserve(&mut self, who: &T::AccountId, amount: T::Balance) -> T::Balance {
+        let reserved = self.balance_reserved(who);
+        let to_move = if amount > reserved { reserved } else { amount };
+
+        let free = self.balance(who);
+        let free_is_fresh = free.is_zero();
+        let new_free = free.checked_add(&to_move).unwrap_or(free);
+
+        if free_is_fresh && !new_free.is_zero() && new_free < T::EXISTENTIAL_DEPOSIT {
+            return amount;
+        }
+
+        let new_reserved = reserved.checked_sub(&to_move).unwrap_or(T::Balance::zero());
+        self.write_reserved(who, new_reserved);
+        self.write_free(who, new_free);
+
+        amount.checked_sub(&to_move).unwrap_or(T::Balance::zero())
+    }
+
+    /// Move up to `amount` from `slashed`'s reserved balance into
+    /// `beneficiary`'s free balance. Returns the part of `amount` that could
+    /// not be repatriated because `slashed` didn't have enough reserved.
+    pub fn repatriate_reserved(
+        &mut self,
+        slashed: &T::AccountId,
+        beneficiary: &T::AccountId,
+        amount: T::Balance,
+    ) -> Result<T::Balance, BalanceError<T::AccountId>> {
+        let reserved = self.balance_reserved(slashed);
+        let to_move = if amount > reserved { reserved } else { amount };
+        let leftover = amount.checked_sub(&to_move).unwrap_or(T::Balance::zero());
+
+        self.write_reserved(slashed, reserved.checked_sub(&to_move).unwrap_or(T::Balance::zero()));
+
+        let beneficiary_free = self.balance(beneficiary);
+        let beneficiary_is_fresh = beneficiary_free.is_zero();
+        let new_beneficiary_free = beneficiary_free
+            .checked_add(&to_move)
+            .ok_or(BalanceError::BalanceOverflow(beneficiary.clone()))?;
+
+        if beneficiary_is_fresh && new_beneficiary_free < T::EXISTENTIAL_DEPOSIT {
+            // The beneficiary never existed and the repatriated amount isn't
+            // enough to bring it above the existential deposit: burn the
+            // dust rather than create a sub-ED account.
+            self.total_issuance = self
+                .total_issuance
+                .checked_sub(&new_beneficiary_free)
+                .unwrap_or(self.total_issuance);
+        } else {
+            self.write_free(beneficiary, new_beneficiary_free);
+        }
+
+        Ok(leftover)
+    }
+
     pub fn transfer(
         &mut self,
         caller: &T::AccountId,
         to: &T::AccountId,
         amount: &T::Balance,
     ) -> Result<(), BalanceError<T::AccountId>> {
-        let mut caller_balance = self.balance(caller);
-        let mut to_balance = self.balance(to);
+        let caller_balance = self.balance(caller);
+        let to_balance = self.balance(to);
 
-        caller_balance = caller_balance
+        let new_caller_balance = caller_balance
             .checked_sub(amount)
             .ok_or(BalanceError::InsufficientBalance)?;
 
-        to_balance = to_balance
+        // Locked funds stay in the account but cannot be spent, even if the
+        // free balance would otherwise cover the transfer.
+        if new_caller_balance < self.locked_balance(caller) {
+            return Err(BalanceError::LiquidityRestrictions);
+        }
+
+        let new_to_balance = to_balance
             .checked_add(amount)
             .ok_or(BalanceError::BalanceOverflow(to.clone()))?;
 
-        self.set_balance(&caller, caller_balance);
-        self.set_balance(&to, to_balance);
+        // The sender must either stay above the existential deposit, or be
+        // reaped to exactly zero - a dust remainder is not allowed to linger.
+        if new_caller_balance < T::EXISTENTIAL_DEPOSIT && !new_caller_balance.is_zero() {
+            return Err(BalanceError::ExistentialDeposit);
+        }
+
+        // A transfer cannot bring a fresh account into existence below the
+        // existential deposit.
+        if to_balance.is_zero() && new_to_balance < T::EXISTENTIAL_DEPOSIT {
+            return Err(BalanceError::ExistentialDeposit);
+        }
+
+        self.set_balance(caller, new_caller_balance);
+        self.set_balance(to, new_to_balance);
+
+        Ok(())
+    }
+
+    /// Keep `total_issuance` in sync with a balance changing from `previous` to `new`.
+    fn adjust_issuance(&mut self, previous: T::Balance, new: T::Balance) {
+        if let Some(increase) = new.checked_sub(&previous) {
+            self.total_issuance = self
+                .total_issuance
+                .checked_add(&increase)
+                .unwrap_or(self.total_issuance);
+        } else if let Some(decrease) = previous.checked_sub(&new) {
+            self.total_issuance = self
+                .total_issuance
+                .checked_sub(&decrease)
+                .unwrap_or(self.total_issuance);
+        }
+    }
+
+    /// Write `amount` for `who`, reaping the account if it would fall below
+    /// the existential deposit and burning whatever dust is left behind.
+    fn apply_balance(&mut self, who: &T::AccountId, amount: T::Balance) {
+        if amount < T::EXISTENTIAL_DEPOSIT {
+            if !amount.is_zero() {
+                self.total_issuance = self
+                    .total_issuance
+                    .checked_sub(&amount)
+                    .unwrap_or(self.total_issuance);
+            }
+            self.balances.remove(who);
+        } else {
+            self.write_free(who, amount);
+        }
+    }
+
+    /// Write `amount` as `who`'s free balance without touching total
+    /// issuance - used by the reserve/unreserve/repatriate paths, which only
+    /// ever move funds between the free and reserved maps.
+    fn write_free(&mut self, who: &T::AccountId, amount: T::Balance) {
+        if amount.is_zero() {
+            self.balances.remove(who);
+        } else {
+            self.balances.insert(who.clone(), amount);
+        }
+    }
+
+    /// Write `amount` as `who`'s reserved balance, removing the entry once
+    /// it reaches zero.
+    fn write_reserved(&mut self, who: &T::AccountId, amount: T::Balance) {
+        if amount.is_zero() {
+            self.reserved.remove(who);
+        } else {
+            self.reserved.insert(who.clone(), amount);
+        }
+    }
+}
+
+impl<T: Config> crate::support::TryState<T::BlockNumber> for Pallet<T> {
+    fn try_state(&self, block: T::BlockNumber) -> Result<(), &'static str> {
+        let free_total = self
+            .balances
+            .values()
+            .fold(T::Balance::zero(), |acc, balance| {
+                acc.checked_add(balance).unwrap_or(acc)
+            });
+        let reserved_total = self
+            .reserved
+            .values()
+            .fold(T::Balance::zero(), |acc, balance| {
+                acc.checked_add(balance).unwrap_or(acc)
+            });
+        let accounted = free_total.checked_add(&reserved_total).unwrap_or(free_total);
+
+        if accounted != self.total_issuance {
+            eprintln!(
+                "try_state failed\n\tPallet: balances\n\tBlock: {:?}\n\tExpected total_issuance: {:?}\n\tActual (free + reserved): {:?}",
+                block, self.total_issuance, accounted
+            );
+            return Err("balances: total_issuance does not match sum of free and reserved balances");
+        }
+
+        Ok(())
+    }
+}
+
+impl<AccountId> From<BalanceError<AccountId>> for &'static str {
+    fn from(error: BalanceError<AccountId>) -> Self {
+        match error {
+            BalanceError::InsufficientBalance => "insufficient balance",
+            BalanceError::BalanceOverflow(_) => "balance overflow",
+            BalanceError::ExistentialDeposit => "balance would drop below the existential deposit",
+            BalanceError::LiquidityRestrictions => "balance is locked and cannot be spent",
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum Call<T: Config> {
+    Transfer {
+        to: T::AccountId,
+        amount: T::Balance,
+    },
+    Reserve {
+        amount: T::Balance,
+    },
+    Unreserve {
+        amount: T::Balance,
+    },
+    RepatriateReserved {
+        beneficiary: T::AccountId,
+        amount: T::Balance,
+    },
+    Slash {
+        who: T::AccountId,
+        amount: T::Balance,
+    },
+}
+
+impl<T: Config> crate::support::Dispatch for Pallet<T> {
+    type Caller = T::AccountId;
+    type Call = Call<T>;
+
+    fn dispatch(
+        &mut self,
+        caller: Self::Caller,
+        call: Self::Call,
+    ) -> crate::support::DispatchResult {
+        match call {
+            Call::Transfer { to, amount } => {
+                self.transfer(&caller, &to, &amount)?;
+            }
+            Call::Reserve { amount } => {
+                self.reserve(&caller, amount)?;
+            }
+            Call::Unreserve { amount } => {
+                self.unreserve(&caller, amount);
+            }
+            Call::RepatriateReserved { beneficiary, amount } => {
+                self.repatriate_reserved(&caller, &beneficiary, amount)?;
+            }
+            Call::Slash { who, amount } => {
+                if caller != T::slash_origin() {
+                    return Err("balances: only the slash origin may slash balances");
+                }
+                self.slash(&who, amount);
+            }
+        }
 
         Ok(())
     }
@@ -56,11 +427,16 @@ impl<T: Config> Pallet<T> {
 
 #[cfg(test)]
 mod tests {
-    use crate::balances::Config;
+    use crate::balances::{BalanceError, Config};
 
     struct BalanceConfig;
     impl Config for BalanceConfig {
         type Balance = u128;
+        const EXISTENTIAL_DEPOSIT: u128 = 0;
+
+        fn slash_origin() -> String {
+            "admin".to_string()
+        }
     }
 
     impl crate::system::Config for BalanceConfig {
@@ -69,6 +445,22 @@ mod tests {
         type Nonce = u32;
     }
 
+    struct BalanceConfigWithEd;
+    impl Config for BalanceConfigWithEd {
+        type Balance = u128;
+        const EXISTENTIAL_DEPOSIT: u128 = 10;
+
+        fn slash_origin() -> String {
+            "admin".to_string()
+        }
+    }
+
+    impl crate::system::Config for BalanceConfigWithEd {
+        type AccountId = String;
+        type BlockNumber = u32;
+        type Nonce = u32;
+    }
+
     #[test]
     fn init_balances() {
         let mut balances = super::Pallet::<BalanceConfig>::new();
@@ -94,4 +486,288 @@ mod tests {
         assert_eq!(balances.balance(&alice), 50);
         assert_eq!(balances.balance(&bob), 50);
     }
+
+    #[test]
+    fn set_balance_tracks_total_issuance() {
+        let mut balances = super::Pallet::<BalanceConfig>::new();
+        let alice = String::from("alice");
+
+        assert_eq!(balances.total_issuance(), 0);
+        balances.set_balance(&alice, 100);
+        assert_eq!(balances.total_issuance(), 100);
+        balances.set_balance(&alice, 40);
+        assert_eq!(balances.total_issuance(), 40);
+    }
+
+    #[test]
+    fn transfer_below_existential_deposit_is_rejected() {
+        let mut balances = super::Pallet::<BalanceConfigWithEd>::new();
+        let alice = String::from("alice");
+        let bob = String::from("bob");
+
+        balances.set_balance(&alice, 100);
+
+        // Leaves alice with 5, which is above 0 but below the ED of 10.
+        let res = balances.transfer(&alice, &bob, &95);
+        assert!(matches!(res, Err(BalanceError::ExistentialDeposit)));
+        assert_eq!(balances.balance(&alice), 100);
+    }
+
+    #[test]
+    fn transfer_reaps_sender_to_zero() {
+        let mut balances = super::Pallet::<BalanceConfigWithEd>::new();
+        let alice = String::from("alice");
+        let bob = String::from("bob");
+
+        balances.set_balance(&alice, 100);
+
+        let res = balances.transfer(&alice, &bob, &100);
+        assert!(res.is_ok());
+        assert_eq!(balances.balance(&alice), 0);
+        assert_eq!(balances.balance(&bob), 100);
+        assert_eq!(balances.total_issuance(), 100);
+    }
+
+    #[test]
+    fn transfer_rejects_fresh_recipient_below_existential_deposit() {
+        let mut balances = super::Pallet::<BalanceConfigWithEd>::new();
+        let alice = String::from("alice");
+        let bob = String::from("bob");
+
+        balances.set_balance(&alice, 100);
+
+        let res = balances.transfer(&alice, &bob, &5);
+        assert!(matches!(res, Err(BalanceError::ExistentialDeposit)));
+        assert_eq!(balances.balance(&bob), 0);
+    }
+
+    #[test]
+    fn reserve_and_unreserve_round_trip() {
+        let mut balances = super::Pallet::<BalanceConfig>::new();
+        let alice = String::from("alice");
+
+        balances.set_balance(&alice, 100);
+
+        assert!(balances.reserve(&alice, 40).is_ok());
+        assert_eq!(balances.balance(&alice), 60);
+        assert_eq!(balances.balance_reserved(&alice), 40);
+        assert_eq!(balances.total_issuance(), 100);
+
+        let leftover = balances.unreserve(&alice, 30);
+        assert_eq!(leftover, 0);
+        assert_eq!(balances.balance(&alice), 90);
+        assert_eq!(balances.balance_reserved(&alice), 10);
+        assert_eq!(balances.total_issuance(), 100);
+    }
+
+    #[test]
+    fn reserve_fails_with_insufficient_free_balance() {
+        let mut balances = super::Pallet::<BalanceConfig>::new();
+        let alice = String::from("alice");
+
+        balances.set_balance(&alice, 10);
+
+        let res = balances.reserve(&alice, 20);
+        assert!(matches!(res, Err(BalanceError::InsufficientBalance)));
+        assert_eq!(balances.balance(&alice), 10);
+        assert_eq!(balances.balance_reserved(&alice), 0);
+    }
+
+    #[test]
+    fn reserve_rejects_leaving_sub_existential_dust() {
+        let mut balances = super::Pallet::<BalanceConfigWithEd>::new();
+        let alice = String::from("alice");
+
+        balances.set_balance(&alice, 100);
+
+        // Leaves 4 free, which is above 0 but below the ED of 10.
+        let res = balances.reserve(&alice, 96);
+        assert!(matches!(res, Err(BalanceError::ExistentialDeposit)));
+        assert_eq!(balances.balance(&alice), 100);
+        assert_eq!(balances.balance_reserved(&alice), 0);
+    }
+
+    #[test]
+    fn unreserve_into_fresh_account_leaves_sub_existential_dust_reserved() {
+        let mut balances = super::Pallet::<BalanceConfigWithEd>::new();
+        let alice = String::from("alice");
+
+        balances.set_balance(&alice, 50);
+        balances.reserve(&alice, 50).unwrap(); // leaves alice with free=0, reserved=50
+
+        // Moving 3 back would create a free balance of 3, below the ED of
+        // 10. Total issuance must stay invariant, so the funds stay
+        // reserved instead of being burned, and nothing is unreserved.
+        let leftover = balances.unreserve(&alice, 3);
+        assert_eq!(leftover, 3);
+        assert_eq!(balances.balance(&alice), 0);
+        assert_eq!(balances.balance_reserved(&alice), 50);
+        assert_eq!(balances.total_issuance(), 50);
+    }
+
+    #[test]
+    fn unreserve_saturates_at_reserved_amount() {
+        let mut balances = super::Pallet::<BalanceConfig>::new();
+        let alice = String::from("alice");
+
+        balances.set_balance(&alice, 100);
+        balances.reserve(&alice, 40).unwrap();
+
+        let leftover = balances.unreserve(&alice, 60);
+        assert_eq!(leftover, 20);
+        assert_eq!(balances.balance(&alice), 100);
+        assert_eq!(balances.balance_reserved(&alice), 0);
+    }
+
+    #[test]
+    fn repatriate_reserved_moves_funds_to_beneficiary() {
+        let mut balances = super::Pallet::<BalanceConfig>::new();
+        let alice = String::from("alice");
+        let bob = String::from("bob");
+
+        balances.set_balance(&alice, 100);
+        balances.reserve(&alice, 50).unwrap();
+
+        let leftover = balances.repatriate_reserved(&alice, &bob, 30).unwrap();
+        assert_eq!(leftover, 0);
+        assert_eq!(balances.balance_reserved(&alice), 20);
+        assert_eq!(balances.balance(&bob), 30);
+        assert_eq!(balances.total_issuance(), 100);
+    }
+
+    #[test]
+    fn repatriate_reserved_burns_dust_for_fresh_beneficiary_below_ed() {
+        let mut balances = super::Pallet::<BalanceConfigWithEd>::new();
+        let alice = String::from("alice");
+        let bob = String::from("bob");
+
+        balances.set_balance(&alice, 100);
+        balances.reserve(&alice, 50).unwrap();
+
+        let leftover = balances.repatriate_reserved(&alice, &bob, 5).unwrap();
+        assert_eq!(leftover, 0);
+        assert_eq!(balances.balance(&bob), 0);
+        assert_eq!(balances.total_issuance(), 95);
+    }
+
+    #[test]
+    fn locked_funds_cannot_be_transferred() {
+        let mut balances = super::Pallet::<BalanceConfig>::new();
+        let alice = String::from("alice");
+        let bob = String::from("bob");
+
+        balances.set_balance(&alice, 100);
+        balances.set_lock(*b"staking!", &alice, 60);
+
+        let res = balances.transfer(&alice, &bob, &50);
+        assert!(matches!(res, Err(BalanceError::LiquidityRestrictions)));
+        assert_eq!(balances.balance(&alice), 100);
+
+        assert!(balances.transfer(&alice, &bob, &30).is_ok());
+        assert_eq!(balances.balance(&alice), 70);
+    }
+
+    #[test]
+    fn overlapping_locks_do_not_sum() {
+        let mut balances = super::Pallet::<BalanceConfig>::new();
+        let alice = String::from("alice");
+
+        balances.set_balance(&alice, 100);
+        balances.set_lock(*b"staking!", &alice, 40);
+        balances.set_lock(*b"voting!!", &alice, 60);
+
+        assert_eq!(balances.locked_balance(&alice), 60);
+
+        balances.remove_lock(*b"voting!!", &alice);
+        assert_eq!(balances.locked_balance(&alice), 40);
+    }
+
+    #[test]
+    fn slash_pulls_from_reserved_before_free() {
+        let mut balances = super::Pallet::<BalanceConfig>::new();
+        let alice = String::from("alice");
+
+        balances.set_balance(&alice, 100);
+        balances.reserve(&alice, 30).unwrap();
+
+        // Reserving 30 leaves 70 free. Slashing 50 exhausts the 30 reserved
+        // first, then pulls the remaining 20 from free: 70 - 20 = 50.
+        let shortfall = balances.slash(&alice, 50);
+        assert_eq!(shortfall, 0);
+        assert_eq!(balances.balance_reserved(&alice), 0);
+        assert_eq!(balances.balance(&alice), 50);
+        assert_eq!(balances.total_issuance(), 50);
+    }
+
+    #[test]
+    fn slash_returns_shortfall_when_account_holds_less_than_amount() {
+        let mut balances = super::Pallet::<BalanceConfig>::new();
+        let alice = String::from("alice");
+
+        balances.set_balance(&alice, 20);
+
+        let shortfall = balances.slash(&alice, 50);
+        assert_eq!(shortfall, 30);
+        assert_eq!(balances.balance(&alice), 0);
+        assert_eq!(balances.total_issuance(), 0);
+    }
+
+    #[test]
+    fn slash_reaps_and_burns_sub_existential_dust() {
+        let mut balances = super::Pallet::<BalanceConfigWithEd>::new();
+        let alice = String::from("alice");
+
+        balances.set_balance(&alice, 100);
+
+        // Slashing 95 would leave 5 free, below the ED of 10.
+        let shortfall = balances.slash(&alice, 95);
+        assert_eq!(shortfall, 0);
+        assert_eq!(balances.balance(&alice), 0);
+        assert_eq!(balances.total_issuance(), 0);
+    }
+
+    #[test]
+    fn dispatch_slash_rejects_unauthorized_caller() {
+        use crate::support::Dispatch;
+
+        let mut balances = super::Pallet::<BalanceConfig>::new();
+        let alice = String::from("alice");
+
+        balances.set_balance(&alice, 100);
+
+        let res = balances.dispatch(
+            "bob".to_string(),
+            super::Call::Slash {
+                who: alice.clone(),
+                amount: 50,
+            },
+        );
+
+        assert_eq!(
+            res,
+            Err("balances: only the slash origin may slash balances")
+        );
+        assert_eq!(balances.balance(&alice), 100);
+    }
+
+    #[test]
+    fn dispatch_slash_succeeds_for_slash_origin() {
+        use crate::support::Dispatch;
+
+        let mut balances = super::Pallet::<BalanceConfig>::new();
+        let alice = String::from("alice");
+
+        balances.set_balance(&alice, 100);
+
+        let res = balances.dispatch(
+            BalanceConfig::slash_origin(),
+            super::Call::Slash {
+                who: alice.clone(),
+                amount: 50,
+            },
+        );
+
+        assert!(res.is_ok());
+        assert_eq!(balances.balance(&alice), 50);
+    }
 }
@@ -1,7 +1,7 @@
 use crate::{
     types::{AccountId, Balance, Block, BlockNumber, Extrinsic, Header, Nonce},
 };
-use crate::support::Dispatch;
+use crate::support::{Dispatch, TryState};
 
 mod balances;
 mod proof_of_existence;
@@ -9,15 +9,15 @@ mod support;
 mod system;
 
 mod types {
-    use crate::{RuntimeCall, support};
+    use crate::{RuntimeCall, support, system};
 
     pub type AccountId = String;
     pub type Balance = u128;
     pub type BlockNumber = u32;
     pub type Nonce = u32;
     pub type Extrinsic = support::Extrinsic<AccountId, RuntimeCall>;
-    pub type Header = support::Header<BlockNumber>;
-    pub type Block = support::Block<Header, Extrinsic>;
+    pub type Header = system::Header<BlockNumber>;
+    pub type Block = system::Block<Header, Extrinsic>;
     pub type Content = String;
 }
 
@@ -34,6 +34,10 @@ pub struct Runtime {
     system: system::Pallet<Runtime>,
     balances: balances::Pallet<Runtime>,
     proof_of_existence: proof_of_existence::Pallet<Runtime>,
+    /// Whether `execute_block` should run the runtime-wide `try_state` check
+    /// after each block. Left on by default; tests that intentionally drive
+    /// the state machine into an inconsistent shape can turn it off.
+    check_invariants: bool,
 }
 
 impl system::Config for Runtime {
@@ -44,6 +48,11 @@ impl system::Config for Runtime {
 
 impl balances::Config for Runtime {
     type Balance = Balance;
+    const EXISTENTIAL_DEPOSIT: Balance = 1;
+
+    fn slash_origin() -> AccountId {
+        String::from("admin")
+    }
 }
 
 impl proof_of_existence::Config for Runtime {
@@ -56,15 +65,22 @@ impl Runtime {
         Self {
             system: system::Pallet::new(),
             balances: balances::Pallet::new(),
-            proof_of_existence: proof_of_existence::Pallet::new()
+            proof_of_existence: proof_of_existence::Pallet::new(),
+            check_invariants: true,
         }
     }
 
+    /// Toggle whether `execute_block` checks invariants after each block.
+    /// Exists so tests can deliberately drive the state machine into an
+    /// inconsistent shape without `execute_block` rejecting the block.
+    #[cfg(test)]
+    pub(crate) fn set_check_invariants(&mut self, check_invariants: bool) {
+        self.check_invariants = check_invariants;
+    }
+
     fn execute_block(&mut self, block: Block) -> support::DispatchResult {
-        self.system.inc_block_number();
-        if block.header.block_number != self.system.block_number() {
-            return Err("block number does not match what is expected");
-        };
+        self.system.initialize_block(&block.header)?;
+
         for (i, Extrinsic { caller, call }) in block.extrinsics.into_iter().enumerate() {
             self.system.inc_nonce(&caller);
             let _ = self.dispatch(caller, call).map_err(|e| {
@@ -75,6 +91,24 @@ impl Runtime {
             });
         }
 
+        self.system.finalize_block();
+
+        if self.check_invariants {
+            self.try_state(block.header.block_number)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl TryState<BlockNumber> for Runtime {
+    // Verify that every pallet's state still upholds its invariants. Run at
+    // the end of each block so broken state is caught deterministically
+    // instead of silently corrupting the chain.
+    fn try_state(&self, block: BlockNumber) -> Result<(), &'static str> {
+        self.system.try_state(block)?;
+        self.balances.try_state(block)?;
+
         Ok(())
     }
 }
@@ -159,3 +193,40 @@ fn main() {
     println!("Runtime: {:?}", runtime);
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn try_state_catches_a_genuine_block_number_mismatch() {
+        let runtime = Runtime::new();
+
+        // A fresh runtime is at block 0, so asserting against block 1 is a
+        // real, unforced invariant violation - no test-only hooks involved.
+        assert!(runtime.try_state(1).is_err());
+        assert!(runtime.try_state(0).is_ok());
+    }
+
+    #[test]
+    fn check_invariants_flag_gates_execute_block() {
+        let mut runtime = Runtime::new();
+        let alice = String::from("alice");
+        runtime.balances.set_balance(&alice, 100);
+
+        // Corrupt `total_issuance` so it no longer matches the sum of
+        // balances, without going through any pallet mutator.
+        runtime.balances.test_set_total_issuance(0);
+
+        let block = Block {
+            header: Header { block_number: 1 },
+            extrinsics: vec![],
+        };
+
+        runtime.set_check_invariants(false);
+        assert!(runtime.clone().execute_block(block.clone()).is_ok());
+
+        runtime.set_check_invariants(true);
+        assert!(runtime.execute_block(block).is_err());
+    }
+}
+
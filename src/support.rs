@@ -0,0 +1,26 @@
+/// A single signed instruction from the outside world: who sent it, and
+/// which pallet call it is asking the runtime to dispatch.
+#[derive(Clone, Debug)]
+pub struct Extrinsic<Caller, Call> {
+    pub caller: Caller,
+    pub call: Call,
+}
+
+/// The result of dispatching a single extrinsic.
+pub type DispatchResult = Result<(), &'static str>;
+
+/// Implemented by anything that can route a call from a caller to the
+/// underlying logic that executes it - the runtime, and each pallet.
+pub trait Dispatch {
+    type Caller;
+    type Call;
+
+    fn dispatch(&mut self, caller: Self::Caller, call: Self::Call) -> DispatchResult;
+}
+
+/// Implemented by the runtime and its pallets to assert that their internal
+/// state still upholds its invariants after a block has executed. Mirrors
+/// Substrate's try-runtime `try_state` checks.
+pub trait TryState<BlockNumber> {
+    fn try_state(&self, block: BlockNumber) -> Result<(), &'static str>;
+}
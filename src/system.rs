@@ -4,9 +4,9 @@ use num::traits::{One, Zero};
 use std::ops::AddAssign;
 
 pub trait Config {
-    type AccountId: Ord + Clone;
-    type BlockNumber: Zero + One + AddAssign + Copy;
-    type Nonce: Zero + One + Copy;
+    type AccountId: Ord + Clone + std::fmt::Debug;
+    type BlockNumber: Zero + One + AddAssign + Copy + PartialEq + std::fmt::Debug;
+    type Nonce: Zero + One + Copy + PartialOrd + std::fmt::Debug;
 }
 
 pub enum SystemError {
@@ -14,10 +14,26 @@ pub enum SystemError {
     NonceOveflow,
 }
 
-#[derive(Debug)]
+/// The block header: consensus-critical metadata that every block carries.
+#[derive(Clone, Debug)]
+pub struct Header<BlockNumber> {
+    pub block_number: BlockNumber,
+}
+
+/// A block is a header plus the extrinsics to execute against it.
+#[derive(Clone, Debug)]
+pub struct Block<Header, Extrinsic> {
+    pub header: Header,
+    pub extrinsics: Vec<Extrinsic>,
+}
+
+#[derive(Clone, Debug)]
 pub struct Pallet<T: Config> {
     block_number: T::BlockNumber,
     nonce: BTreeMap<T::AccountId, T::Nonce>,
+    /// A snapshot of `nonce` taken by `initialize_block`, so `try_state` can
+    /// assert that no account's nonce went backwards during the block.
+    nonces_at_block_start: BTreeMap<T::AccountId, T::Nonce>,
 }
 
 impl<T: Config> Pallet<T> {
@@ -26,6 +42,7 @@ impl<T: Config> Pallet<T> {
         Self {
             block_number: T::BlockNumber::zero(),
             nonce: BTreeMap::new(),
+            nonces_at_block_start: BTreeMap::new(),
         }
     }
 
@@ -41,10 +58,66 @@ impl<T: Config> Pallet<T> {
         let new_nonce = nonce + T::Nonce::one();
         self.nonce.insert(caller.clone(), new_nonce);
     }
+
+    /// Validate `header` against the pallet's current block number and, if
+    /// it lines up, advance to it. This is the one place block-number
+    /// validation happens, so callers no longer hand-check it themselves.
+    pub fn initialize_block(&mut self, header: &Header<T::BlockNumber>) -> Result<(), &'static str> {
+        let expected = self.block_number + T::BlockNumber::one();
+        if header.block_number != expected {
+            return Err("block number does not match what is expected");
+        }
+
+        self.block_number = expected;
+        self.nonces_at_block_start = self.nonce.clone();
+
+        Ok(())
+    }
+
+    /// Force a specific nonce for `who`, bypassing `inc_nonce`. Exists only so
+    /// tests can simulate a nonce having gone backwards and observe
+    /// `try_state` catching it.
+    #[cfg(test)]
+    pub(crate) fn test_set_nonce(&mut self, who: &T::AccountId, nonce: T::Nonce) {
+        self.nonce.insert(who.clone(), nonce);
+    }
+
+    /// Called once a block's extrinsics have all been dispatched. Reserved
+    /// for consensus-critical wrap-up (e.g. a digest) as the header grows;
+    /// there is nothing to do yet.
+    pub fn finalize_block(&mut self) {}
+}
+
+impl<T: Config> crate::support::TryState<T::BlockNumber> for Pallet<T> {
+    fn try_state(&self, block: T::BlockNumber) -> Result<(), &'static str> {
+        if self.block_number() != block {
+            eprintln!(
+                "try_state failed\n\tPallet: system\n\tExpected block number: {:?}\n\tActual block number: {:?}",
+                block,
+                self.block_number()
+            );
+            return Err("system: block number is inconsistent with the finalized block");
+        }
+
+        for (who, start_nonce) in &self.nonces_at_block_start {
+            let current_nonce = *self.nonce.get(who).unwrap_or(&T::Nonce::zero());
+            if current_nonce < *start_nonce {
+                eprintln!(
+                    "try_state failed\n\tPallet: system\n\tAccount: {:?}\n\tNonce at block start: {:?}\n\tCurrent nonce: {:?}",
+                    who, start_nonce, current_nonce
+                );
+                return Err("system: a nonce decreased during the block");
+            }
+        }
+
+        Ok(())
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use crate::support::TryState;
+
     struct TestConfig;
     impl super::Config for TestConfig {
         type AccountId = String;
@@ -62,4 +135,35 @@ mod tests {
         assert_eq!(system.nonce.get("alice"), Some(&1));
         assert_eq!(system.nonce.get("bob"), None);
     }
+
+    #[test]
+    fn initialize_block_validates_and_advances() {
+        let mut system = super::Pallet::<TestConfig>::new();
+
+        let res = system.initialize_block(&super::Header { block_number: 1 });
+        assert!(res.is_ok());
+        assert_eq!(system.block_number(), 1);
+
+        let res = system.initialize_block(&super::Header { block_number: 5 });
+        assert!(res.is_err());
+        assert_eq!(system.block_number(), 1);
+    }
+
+    #[test]
+    fn try_state_catches_a_decreasing_nonce() {
+        let alice = "alice".to_string();
+        let mut system = super::Pallet::<TestConfig>::new();
+
+        system.initialize_block(&super::Header { block_number: 1 }).unwrap();
+        system.inc_nonce(&alice);
+
+        // Start a second block, snapshotting alice's nonce (1) as the floor
+        // for the rest of this block.
+        system.initialize_block(&super::Header { block_number: 2 }).unwrap();
+        assert!(system.try_state(2).is_ok());
+
+        // Simulate the nonce having been rolled back below that floor.
+        system.test_set_nonce(&alice, 0);
+        assert!(system.try_state(2).is_err());
+    }
 }